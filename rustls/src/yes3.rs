@@ -6,11 +6,32 @@ use core::{
 };
 use std::{result::Result, vec::Vec};
 
+use sha2::{Digest, Sha256};
+
 use crate::{
-    msgs::enums::{ECPointFormat, ExtensionType, NamedGroup},
-    CipherSuite, ProtocolVersion,
+    msgs::{
+        enums::{ECPointFormat, ExtensionType, NamedGroup},
+        handshake::{ClientExtension, ClientHelloPayload, ServerExtension, ServerHelloPayload},
+    },
+    CipherSuite, ProtocolVersion, SignatureScheme,
 };
 
+/// The reserved GREASE values (RFC 8701): every 16-bit value of the form
+/// `0x?a?a` where both bytes are equal. TLS clients scatter these into
+/// cipher, extension and supported-group lists to prevent protocol
+/// ossification; a JA3 fingerprint that retains them is unstable across
+/// connections from the same client. `Ja3::normalized` and `Ja3S::normalized`
+/// strip them; `hash()` digests the struct as it is, without stripping.
+/// JA4 always leaves them out of its counts and digests.
+const GREASE: [u16; 16] = [
+    0x0a0a, 0x1a1a, 0x2a2a, 0x3a3a, 0x4a4a, 0x5a5a, 0x6a6a, 0x7a7a, 0x8a8a, 0x9a9a, 0xaaaa, 0xbaba,
+    0xcaca, 0xdada, 0xeaea, 0xfafa,
+];
+
+fn is_grease(value: u16) -> bool {
+    GREASE.contains(&value)
+}
+
 /// Error(s) that can show up when (de)serializing Ja3 fingerprints
 #[derive(core::fmt::Debug)]
 pub struct Error;
@@ -22,6 +43,9 @@ impl<T: std::error::Error> From<T> for Error {
 }
 
 // Utils
+// An empty field is a valid, empty list (e.g. a client that sent no
+// extensions); a non-empty field with a non-numeric or out-of-range
+// `-`-separated token is a parse error, not a silently-dropped entry.
 macro_rules! from_str {
     ($ty:path; $input:expr) => {
         match $input.is_empty() {
@@ -97,6 +121,78 @@ impl Display for Ja3 {
     }
 }
 
+impl Ja3 {
+    /// Compute the canonical JA3 fingerprint: the MD5 digest of this
+    /// struct's `Display` output, as a 32-char lowercase hex string.
+    pub fn hash(&self) -> String {
+        format!("{:x}", md5::compute(self.to_string()))
+    }
+
+    /// Return a copy of this fingerprint with GREASE-valued ciphers,
+    /// extensions and supported groups removed, per the canonical JA3
+    /// algorithm. The raw vectors are left untouched; only the returned
+    /// copy is filtered.
+    pub fn normalized(&self) -> Self {
+        Self {
+            ssl_versions: self.ssl_versions.clone(),
+            ciphers: self
+                .ciphers
+                .iter()
+                .filter(|x| !is_grease(x.get_u16()))
+                .cloned()
+                .collect(),
+            ssl_extensions: self
+                .ssl_extensions
+                .iter()
+                .filter(|x| !is_grease(x.get_u16()))
+                .cloned()
+                .collect(),
+            elliptic_curves: self
+                .elliptic_curves
+                .iter()
+                .filter(|x| !is_grease(x.get_u16()))
+                .cloned()
+                .collect(),
+            elliptic_curve_point_formats: self.elliptic_curve_point_formats.clone(),
+        }
+    }
+
+    /// Derive a JA3 fingerprint directly from a parsed `ClientHello`,
+    /// preserving the wire order of its cipher suites and extensions.
+    pub fn from_client_hello(hello: &ClientHelloPayload) -> Self {
+        let elliptic_curves = hello
+            .extensions
+            .iter()
+            .find_map(|ext| match ext {
+                ClientExtension::EllipticCurves(groups) => Some(groups.clone()),
+                _ => None,
+            })
+            .unwrap_or_default();
+
+        let elliptic_curve_point_formats = hello
+            .extensions
+            .iter()
+            .find_map(|ext| match ext {
+                ClientExtension::ECPointFormats(formats) => Some(formats.clone()),
+                _ => None,
+            })
+            .unwrap_or_default();
+
+        Self {
+            ssl_versions: vec![hello.client_version],
+            ciphers: hello.cipher_suites.clone(),
+            ssl_extensions: hello
+                .extensions
+                .iter()
+                .map(ClientExtension::get_type)
+                .collect(),
+            elliptic_curves,
+            elliptic_curve_point_formats,
+        }
+    }
+}
+
+#[derive(core::cmp::PartialEq, Debug, Clone)]
 /// Ja3 Server fingerprint
 ///
 /// <https://github.com/salesforce/ja3>
@@ -113,7 +209,7 @@ impl FromStr for Ja3S {
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut c = s.splitn(5, ',');
+        let mut c = s.splitn(3, ',');
         let parts: [&str; 3] = <[(); 3]>::default().map(|_| c.next().unwrap_or(""));
         Ok(Self {
             ssl_versions: from_str!(u16; parts[0])?,
@@ -133,3 +229,583 @@ impl Display for Ja3S {
         Ok(())
     }
 }
+
+impl Ja3S {
+    /// Compute the canonical JA3S fingerprint: the MD5 digest of this
+    /// struct's `Display` output, as a 32-char lowercase hex string.
+    pub fn hash(&self) -> String {
+        format!("{:x}", md5::compute(self.to_string()))
+    }
+
+    /// Return a copy of this fingerprint with GREASE-valued ciphers and
+    /// extensions removed, per the canonical JA3 algorithm. The raw
+    /// vectors are left untouched; only the returned copy is filtered.
+    pub fn normalized(&self) -> Self {
+        Self {
+            ssl_versions: self.ssl_versions.clone(),
+            ciphers: self
+                .ciphers
+                .iter()
+                .filter(|x| !is_grease(x.get_u16()))
+                .cloned()
+                .collect(),
+            ssl_extensions: self
+                .ssl_extensions
+                .iter()
+                .filter(|x| !is_grease(x.get_u16()))
+                .cloned()
+                .collect(),
+        }
+    }
+
+    /// Derive a JA3S fingerprint directly from a parsed `ServerHello`,
+    /// using the negotiated version, the single selected cipher suite,
+    /// and the extensions in the order the server sent them.
+    pub fn from_server_hello(hello: &ServerHelloPayload) -> Self {
+        Self {
+            ssl_versions: vec![hello.legacy_version],
+            ciphers: vec![hello.cipher_suite],
+            ssl_extensions: hello
+                .extensions
+                .iter()
+                .map(ServerExtension::get_type)
+                .collect(),
+        }
+    }
+}
+
+/// The transport a `ClientHello` was observed over, used for the leading
+/// character of a JA4 fingerprint's `a` section.
+#[derive(core::cmp::PartialEq, Debug, Clone, Copy)]
+pub enum Ja4Transport {
+    /// TLS over TCP.
+    Tcp,
+    /// TLS over QUIC.
+    Quic,
+}
+
+impl Ja4Transport {
+    fn as_char(self) -> char {
+        match self {
+            Self::Tcp => 't',
+            Self::Quic => 'q',
+        }
+    }
+}
+
+/// JA4 Client fingerprint
+///
+/// <https://github.com/FoxIO-LLC/ja4>
+#[derive(core::cmp::PartialEq, Debug, Clone)]
+pub struct Ja4 {
+    /// Transport the ClientHello was observed over.
+    pub transport: Ja4Transport,
+    /// Negotiated TLS version.
+    pub version: ProtocolVersion,
+    /// Whether an SNI extension was present.
+    pub has_sni: bool,
+    /// Cipher(s), in wire order.
+    pub ciphers: Vec<CipherSuite>,
+    /// Extension(s), in wire order.
+    pub extensions: Vec<ExtensionType>,
+    /// Signature algorithm(s) from the `signature_algorithms` extension, in wire order.
+    pub signature_algorithms: Vec<SignatureScheme>,
+    /// First ALPN value offered in the ClientHello, if any.
+    pub alpn: Option<Vec<u8>>,
+}
+
+fn ja4_version_code(version: ProtocolVersion) -> &'static str {
+    match version.get_u16() {
+        0x0304 => "13",
+        0x0303 => "12",
+        0x0302 => "11",
+        0x0301 => "10",
+        0x0300 => "s3",
+        _ => "00",
+    }
+}
+
+impl Ja4 {
+    /// Derive a JA4 fingerprint from a parsed `ClientHello` observed over
+    /// `transport`. The negotiated version is taken from the
+    /// `supported_versions` extension when present (the highest offered
+    /// value), falling back to `client_version` otherwise.
+    pub fn from_client_hello(hello: &ClientHelloPayload, transport: Ja4Transport) -> Self {
+        let version = hello
+            .extensions
+            .iter()
+            .find_map(|ext| match ext {
+                ClientExtension::SupportedVersions(versions) => versions
+                    .iter()
+                    .copied()
+                    .max_by_key(ProtocolVersion::get_u16),
+                _ => None,
+            })
+            .unwrap_or(hello.client_version);
+
+        let has_sni = hello
+            .extensions
+            .iter()
+            .any(|ext| matches!(ext, ClientExtension::ServerName(_)));
+
+        let signature_algorithms = hello
+            .extensions
+            .iter()
+            .find_map(|ext| match ext {
+                ClientExtension::SignatureAlgorithms(schemes) => Some(schemes.clone()),
+                _ => None,
+            })
+            .unwrap_or_default();
+
+        let alpn = hello.extensions.iter().find_map(|ext| match ext {
+            ClientExtension::Protocols(protocols) => protocols.first().map(|p| p.as_ref().to_vec()),
+            _ => None,
+        });
+
+        Self {
+            transport,
+            version,
+            has_sni,
+            ciphers: hello.cipher_suites.clone(),
+            extensions: hello
+                .extensions
+                .iter()
+                .map(ClientExtension::get_type)
+                .collect(),
+            signature_algorithms,
+            alpn,
+        }
+    }
+
+    /// Compute the JA4 fingerprint: the `a_b_c` string described by
+    /// `Display`, sections `b` and `c` being truncated SHA-256 digests.
+    pub fn hash(&self) -> String {
+        self.to_string()
+    }
+
+    fn non_grease_ciphers(&self) -> Vec<u16> {
+        self.ciphers
+            .iter()
+            .map(CipherSuite::get_u16)
+            .filter(|x| !is_grease(*x))
+            .collect()
+    }
+
+    fn non_grease_extensions(&self) -> Vec<u16> {
+        self.extensions
+            .iter()
+            .map(ExtensionType::get_u16)
+            .filter(|x| !is_grease(*x))
+            .collect()
+    }
+}
+
+impl Display for Ja4 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let non_grease_ciphers = self.non_grease_ciphers();
+        let non_grease_extensions = self.non_grease_extensions();
+
+        let alpn_chars = match &self.alpn {
+            Some(proto) if !proto.is_empty() => {
+                let first = *proto.first().unwrap() as char;
+                let last = *proto.last().unwrap() as char;
+                format!("{first}{last}")
+            }
+            _ => "00".to_string(),
+        };
+
+        write!(
+            f,
+            "{}{}{}{:02}{:02}{}_",
+            self.transport.as_char(),
+            ja4_version_code(self.version),
+            if self.has_sni { 'd' } else { 'i' },
+            non_grease_ciphers.len().min(99),
+            non_grease_extensions.len().min(99),
+            alpn_chars,
+        )?;
+
+        let mut sorted_ciphers = non_grease_ciphers;
+        sorted_ciphers.sort_unstable();
+        let cipher_list = sorted_ciphers
+            .iter()
+            .map(|x| format!("{x:04x}"))
+            .collect::<Vec<_>>()
+            .join(",");
+        f.write_str(&sha256_prefix(&cipher_list))?;
+        f.write_char('_')?;
+
+        let sni_type = ExtensionType::ServerName.get_u16();
+        let alpn_type = ExtensionType::ALProtocolNegotiation.get_u16();
+        let mut sorted_extensions: Vec<u16> = non_grease_extensions
+            .into_iter()
+            .filter(|x| *x != sni_type && *x != alpn_type)
+            .collect();
+        sorted_extensions.sort_unstable();
+        let extension_list = sorted_extensions
+            .iter()
+            .map(|x| format!("{x:04x}"))
+            .collect::<Vec<_>>()
+            .join(",");
+        let sigalg_list = self
+            .signature_algorithms
+            .iter()
+            .map(|x| format!("{:04x}", x.get_u16()))
+            .collect::<Vec<_>>()
+            .join(",");
+        f.write_str(&sha256_prefix(&format!("{extension_list}_{sigalg_list}")))?;
+
+        Ok(())
+    }
+}
+
+fn sha256_prefix(input: &str) -> String {
+    let digest = Sha256::digest(input.as_bytes());
+    let hex = format!("{digest:x}");
+    hex[..12].to_string()
+}
+
+#[cfg(test)]
+mod ja4_tests {
+    use super::*;
+    use crate::msgs::handshake::ProtocolName;
+
+    fn base_ja4() -> Ja4 {
+        Ja4 {
+            transport: Ja4Transport::Tcp,
+            version: ProtocolVersion::TLSv1_3,
+            has_sni: true,
+            ciphers: vec![CipherSuite::from(0x1301), CipherSuite::from(0x1302)],
+            extensions: vec![
+                ExtensionType::from(0x0000),
+                ExtensionType::from(0x000d),
+                ExtensionType::from(0x0010),
+                ExtensionType::from(0x002b),
+            ],
+            signature_algorithms: vec![
+                SignatureScheme::from(0x0403),
+                SignatureScheme::from(0x0804),
+            ],
+            alpn: Some(b"h2".to_vec()),
+        }
+    }
+
+    #[test]
+    fn ja4_matches_known_reference_vector() {
+        let ja4 = base_ja4();
+        assert_eq!(ja4.to_string(), "t13d0204h2_62ed6f6ca7ad_ef5f37ab036a");
+        assert_eq!(ja4.hash(), ja4.to_string());
+    }
+
+    #[test]
+    fn ja4_filters_grease_before_hashing() {
+        let mut with_grease = base_ja4();
+        with_grease.ciphers.insert(0, CipherSuite::from(0x0a0a));
+        with_grease.extensions.push(ExtensionType::from(0x2a2a));
+
+        assert_eq!(with_grease.to_string(), base_ja4().to_string());
+    }
+
+    #[test]
+    fn ja4_from_client_hello_reads_sni_version_and_alpn() {
+        let with_sni = ClientHelloPayload {
+            client_version: ProtocolVersion::TLSv1_2,
+            cipher_suites: vec![CipherSuite::from(0x1301), CipherSuite::from(0x1302)],
+            extensions: vec![
+                ClientExtension::ServerName(Vec::new()),
+                ClientExtension::SupportedVersions(vec![
+                    ProtocolVersion::TLSv1_2,
+                    ProtocolVersion::TLSv1_3,
+                ]),
+                ClientExtension::Protocols(vec![ProtocolName(b"h2".to_vec())]),
+            ],
+        };
+
+        let ja4 = Ja4::from_client_hello(&with_sni, Ja4Transport::Tcp);
+        assert!(ja4.has_sni);
+        // SupportedVersions overrides the legacy client_version with the highest offered version.
+        assert_eq!(ja4.version, ProtocolVersion::TLSv1_3);
+        assert_eq!(ja4.alpn, Some(b"h2".to_vec()));
+
+        let without_sni = ClientHelloPayload {
+            client_version: ProtocolVersion::TLSv1_2,
+            cipher_suites: vec![CipherSuite::from(0x1301)],
+            extensions: Vec::new(),
+        };
+
+        let ja4 = Ja4::from_client_hello(&without_sni, Ja4Transport::Tcp);
+        assert!(!ja4.has_sni);
+        assert_eq!(ja4.version, ProtocolVersion::TLSv1_2);
+        assert_eq!(ja4.alpn, None);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_ja3s() -> Vec<Ja3> {
+        vec![
+            Ja3 {
+                ssl_versions: vec![],
+                ciphers: vec![],
+                ssl_extensions: vec![],
+                elliptic_curves: vec![],
+                elliptic_curve_point_formats: vec![],
+            },
+            Ja3 {
+                ssl_versions: vec![ProtocolVersion::TLSv1_2],
+                ciphers: vec![CipherSuite::from(0x1301), CipherSuite::from(0x1302)],
+                ssl_extensions: vec![ExtensionType::from(0x000a), ExtensionType::from(0x000b)],
+                elliptic_curves: vec![NamedGroup::from(0x0017)],
+                elliptic_curve_point_formats: vec![ECPointFormat::from(0)],
+            },
+            Ja3 {
+                ssl_versions: vec![ProtocolVersion::TLSv1_3, ProtocolVersion::TLSv1_2],
+                ciphers: (0..10).map(CipherSuite::from).collect(),
+                ssl_extensions: (0..5).map(ExtensionType::from).collect(),
+                elliptic_curves: vec![NamedGroup::from(0x0017), NamedGroup::from(0x0018)],
+                elliptic_curve_point_formats: vec![ECPointFormat::from(0), ECPointFormat::from(1)],
+            },
+        ]
+    }
+
+    #[test]
+    fn ja3_round_trips_through_display_and_from_str() {
+        for ja3 in sample_ja3s() {
+            let reparsed = Ja3::from_str(&ja3.to_string()).expect("valid Ja3 string");
+            assert_eq!(reparsed, ja3);
+        }
+    }
+
+    #[test]
+    fn ja3s_round_trips_through_display_and_from_str() {
+        let ja3s = Ja3S {
+            ssl_versions: vec![ProtocolVersion::TLSv1_3],
+            ciphers: vec![CipherSuite::from(0x1301)],
+            ssl_extensions: vec![ExtensionType::from(0x002b), ExtensionType::from(0x0033)],
+        };
+        let reparsed = Ja3S::from_str(&ja3s.to_string()).expect("valid Ja3S string");
+        assert_eq!(reparsed, ja3s);
+    }
+
+    /// A small deterministic xorshift PRNG, so the generated-struct round-trip
+    /// test below is reproducible without pulling in a quickcheck/proptest
+    /// dependency (this crate has no dev-dependency slot for one here).
+    struct Xorshift64(u64);
+
+    impl Xorshift64 {
+        fn next_u64(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        fn next_u16(&mut self) -> u16 {
+            self.next_u64() as u16
+        }
+
+        fn next_len(&mut self, max: usize) -> usize {
+            (self.next_u64() as usize) % (max + 1)
+        }
+
+        fn vec_of<T>(&mut self, max_len: usize, mut f: impl FnMut(&mut Self) -> T) -> Vec<T> {
+            let len = self.next_len(max_len);
+            (0..len).map(|_| f(self)).collect()
+        }
+    }
+
+    #[test]
+    fn ja3_round_trips_across_generated_structs() {
+        let mut rng = Xorshift64(0x9e3779b97f4a7c15);
+        for _ in 0..200 {
+            let ja3 = Ja3 {
+                ssl_versions: rng.vec_of(2, |r| ProtocolVersion::from(r.next_u16())),
+                ciphers: rng.vec_of(8, |r| CipherSuite::from(r.next_u16())),
+                ssl_extensions: rng.vec_of(8, |r| ExtensionType::from(r.next_u16())),
+                elliptic_curves: rng.vec_of(4, |r| NamedGroup::from(r.next_u16())),
+                elliptic_curve_point_formats: rng
+                    .vec_of(4, |r| ECPointFormat::from(r.next_u16() as u8)),
+            };
+            let reparsed = Ja3::from_str(&ja3.to_string()).expect("generated Ja3 round-trips");
+            assert_eq!(reparsed, ja3);
+        }
+    }
+
+    #[test]
+    fn ja3s_round_trips_across_generated_structs() {
+        let mut rng = Xorshift64(0xbf58476d1ce4e5b9);
+        for _ in 0..200 {
+            let ja3s = Ja3S {
+                ssl_versions: rng.vec_of(2, |r| ProtocolVersion::from(r.next_u16())),
+                ciphers: rng.vec_of(8, |r| CipherSuite::from(r.next_u16())),
+                ssl_extensions: rng.vec_of(8, |r| ExtensionType::from(r.next_u16())),
+            };
+            let reparsed = Ja3S::from_str(&ja3s.to_string()).expect("generated Ja3S round-trips");
+            assert_eq!(reparsed, ja3s);
+        }
+    }
+
+    #[test]
+    fn empty_field_parses_to_empty_vec() {
+        let ja3 = Ja3::from_str(",,,,").expect("all-empty fields are valid");
+        assert!(ja3.ssl_versions.is_empty());
+        assert!(ja3.ciphers.is_empty());
+        assert!(ja3.ssl_extensions.is_empty());
+        assert!(ja3.elliptic_curves.is_empty());
+        assert!(ja3.elliptic_curve_point_formats.is_empty());
+    }
+
+    #[test]
+    fn non_numeric_token_is_an_error() {
+        assert!(Ja3::from_str("not-a-version,,,,").is_err());
+    }
+
+    #[test]
+    fn out_of_range_token_is_an_error() {
+        // elliptic_curve_point_formats parses as u8, so 999 overflows it.
+        assert!(Ja3::from_str(",,,,999").is_err());
+    }
+
+    #[test]
+    fn ja3_normalized_strips_only_grease_entries() {
+        let ja3 = Ja3 {
+            ssl_versions: vec![ProtocolVersion::TLSv1_2],
+            ciphers: vec![CipherSuite::from(0x0a0a), CipherSuite::from(0x1301)],
+            ssl_extensions: vec![ExtensionType::from(0x1a1a), ExtensionType::from(0x000a)],
+            elliptic_curves: vec![NamedGroup::from(0x2a2a), NamedGroup::from(0x0017)],
+            elliptic_curve_point_formats: vec![ECPointFormat::from(0), ECPointFormat::from(1)],
+        };
+
+        let normalized = ja3.normalized();
+        assert_eq!(normalized.ciphers, vec![CipherSuite::from(0x1301)]);
+        assert_eq!(normalized.ssl_extensions, vec![ExtensionType::from(0x000a)]);
+        assert_eq!(normalized.elliptic_curves, vec![NamedGroup::from(0x0017)]);
+        // GREASE never appears in point formats, so these are untouched.
+        assert_eq!(
+            normalized.elliptic_curve_point_formats,
+            ja3.elliptic_curve_point_formats
+        );
+    }
+
+    #[test]
+    fn ja3s_normalized_strips_only_grease_entries() {
+        let ja3s = Ja3S {
+            ssl_versions: vec![ProtocolVersion::TLSv1_2],
+            ciphers: vec![CipherSuite::from(0x3a3a), CipherSuite::from(0x1301)],
+            ssl_extensions: vec![ExtensionType::from(0x4a4a), ExtensionType::from(0x002b)],
+        };
+
+        let normalized = ja3s.normalized();
+        assert_eq!(normalized.ciphers, vec![CipherSuite::from(0x1301)]);
+        assert_eq!(normalized.ssl_extensions, vec![ExtensionType::from(0x002b)]);
+    }
+
+    #[test]
+    fn ja3_hash_matches_known_md5_vector() {
+        let ja3 = Ja3 {
+            ssl_versions: vec![ProtocolVersion::from(769)],
+            ciphers: vec![CipherSuite::from(4), CipherSuite::from(5)],
+            ssl_extensions: vec![ExtensionType::from(10), ExtensionType::from(11)],
+            elliptic_curves: vec![NamedGroup::from(23), NamedGroup::from(24)],
+            elliptic_curve_point_formats: vec![ECPointFormat::from(0)],
+        };
+        assert_eq!(ja3.to_string(), "769,4-5,10-11,23-24,0");
+        assert_eq!(ja3.hash(), "8412f01e614cd7944700be49ae968adb");
+    }
+
+    #[test]
+    fn ja3s_hash_matches_known_md5_vector() {
+        let ja3s = Ja3S {
+            ssl_versions: vec![ProtocolVersion::from(771)],
+            ciphers: vec![CipherSuite::from(49)],
+            ssl_extensions: vec![ExtensionType::from(35)],
+        };
+        assert_eq!(ja3s.to_string(), "771,49,35");
+        assert_eq!(ja3s.hash(), "b2eabb84ee7a62e0f77778104740edb2");
+    }
+
+    #[test]
+    fn ja3_from_client_hello_preserves_wire_order() {
+        let hello = ClientHelloPayload {
+            client_version: ProtocolVersion::TLSv1_2,
+            cipher_suites: vec![CipherSuite::from(0x1301), CipherSuite::from(0x1302)],
+            extensions: vec![
+                ClientExtension::SignatureAlgorithms(vec![SignatureScheme::from(0x0403)]),
+                ClientExtension::EllipticCurves(vec![
+                    NamedGroup::from(0x0017),
+                    NamedGroup::from(0x0018),
+                ]),
+                ClientExtension::ECPointFormats(vec![ECPointFormat::from(0), ECPointFormat::from(1)]),
+                ClientExtension::ServerName(Vec::new()),
+            ],
+        };
+
+        let ja3 = Ja3::from_client_hello(&hello);
+        assert_eq!(ja3.ssl_versions, vec![ProtocolVersion::TLSv1_2]);
+        assert_eq!(
+            ja3.ciphers,
+            vec![CipherSuite::from(0x1301), CipherSuite::from(0x1302)]
+        );
+        assert_eq!(
+            ja3.ssl_extensions,
+            vec![
+                ExtensionType::from(0x000d),
+                ExtensionType::from(0x000a),
+                ExtensionType::from(0x000b),
+                ExtensionType::from(0x0000),
+            ]
+        );
+        assert_eq!(
+            ja3.elliptic_curves,
+            vec![NamedGroup::from(0x0017), NamedGroup::from(0x0018)]
+        );
+        assert_eq!(
+            ja3.elliptic_curve_point_formats,
+            vec![ECPointFormat::from(0), ECPointFormat::from(1)]
+        );
+    }
+
+    #[test]
+    fn ja3_from_client_hello_defaults_missing_curve_extensions_to_empty() {
+        let hello = ClientHelloPayload {
+            client_version: ProtocolVersion::TLSv1_3,
+            cipher_suites: vec![CipherSuite::from(0x1301)],
+            extensions: vec![ClientExtension::ServerName(Vec::new())],
+        };
+
+        let ja3 = Ja3::from_client_hello(&hello);
+        assert!(ja3.elliptic_curves.is_empty());
+        assert!(ja3.elliptic_curve_point_formats.is_empty());
+        assert_eq!(ja3.ssl_extensions, vec![ExtensionType::from(0x0000)]);
+    }
+
+    #[test]
+    fn ja3s_from_server_hello_picks_version_cipher_and_extension_order() {
+        let hello = ServerHelloPayload {
+            legacy_version: ProtocolVersion::TLSv1_2,
+            cipher_suite: CipherSuite::from(0x1301),
+            extensions: vec![
+                ServerExtension::SessionTicketAck,
+                ServerExtension::ServerNameAck,
+                ServerExtension::ExtendedMasterSecretAck,
+            ],
+        };
+
+        let ja3s = Ja3S::from_server_hello(&hello);
+        assert_eq!(ja3s.ssl_versions, vec![ProtocolVersion::TLSv1_2]);
+        assert_eq!(ja3s.ciphers, vec![CipherSuite::from(0x1301)]);
+
+        // Wire order preserved, not re-sorted or deduplicated.
+        let expected: Vec<ExtensionType> = hello
+            .extensions
+            .iter()
+            .map(ServerExtension::get_type)
+            .collect();
+        assert_eq!(ja3s.ssl_extensions, expected);
+        assert_eq!(ja3s.ssl_extensions.len(), 3);
+        assert_ne!(ja3s.ssl_extensions[0], ja3s.ssl_extensions[1]);
+        assert_ne!(ja3s.ssl_extensions[1], ja3s.ssl_extensions[2]);
+    }
+}